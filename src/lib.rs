@@ -12,10 +12,10 @@
 //! /// unsafe because: trusts the permutation to be correct
 //! unsafe fn apply_permutation<T>(perm: &mut [usize], v: &mut [T]) {
 //!     debug_assert_eq!(perm.len(), v.len());
-//!     
+//!
 //!     // use unchecked (in reality, debug-checked) indexing throughout
 //!     let mut perm = unchecked_index(perm);
-//!     
+//!
 //!     for i in 0..perm.len() {
 //!         let mut current = i;
 //!         while i != perm[current] {
@@ -55,6 +55,40 @@ pub unsafe fn unchecked_index<T>(v: T) -> UncheckedIndex<T>
     UncheckedIndex(v)
 }
 
+/// Guard whose destructor aborts the process; used by [`debug_index_check`]
+/// under the `debug_abort` feature to turn a failing debug assertion into a
+/// hard, non-unwinding stop.
+#[cfg(all(debug_assertions, feature = "debug_abort"))]
+struct AbortGuard;
+
+#[cfg(all(debug_assertions, feature = "debug_abort"))]
+impl Drop for AbortGuard {
+    fn drop(&mut self) {
+        std::process::abort();
+    }
+}
+
+/// Run the debug-mode index check *in place*, so that `#[track_caller]`
+/// propagates the caller's indexing site into `assert_indexable_with` (a
+/// wrapping closure would sever the chain and report this crate instead).
+///
+/// With the `debug_abort` feature enabled the check is wrapped in an
+/// [`AbortGuard`]: if the assertion unwinds, the guard's destructor runs during
+/// the unwind and aborts the process, mirroring the `debug_assert_nounwind!`
+/// approach `core` uses in its own `_unchecked` functions.
+macro_rules! debug_index_check {
+    ($v:expr, $index:expr) => {
+        #[cfg(debug_assertions)]
+        {
+            #[cfg(feature = "debug_abort")]
+            let _guard = AbortGuard;
+            $v.assert_indexable_with($index);
+            #[cfg(feature = "debug_abort")]
+            ::std::mem::forget(_guard);
+        }
+    };
+}
+
 /// Access the element(s) at `index`, without bounds checks!
 ///
 /// *Note:* Will use *debug assertions* to check that the index is actually
@@ -64,11 +98,11 @@ pub unsafe fn unchecked_index<T>(v: T) -> UncheckedIndex<T>
 ///
 /// The caller must ensure that `index` is always in bounds of the
 /// underlying container.
+#[track_caller]
 pub unsafe fn get_unchecked<T: ?Sized, I>(v: &T, index: I) -> &T::Output
-    where T: GetUnchecked<I>
+    where T: SliceIndex<I>
 {
-    #[cfg(debug_assertions)]
-    v.assert_indexable_with(&index);
+    debug_index_check!(v, &index);
     v.get_unchecked(index)
 }
 
@@ -81,11 +115,11 @@ pub unsafe fn get_unchecked<T: ?Sized, I>(v: &T, index: I) -> &T::Output
 ///
 /// The caller must ensure that `index` is always in bounds of the
 /// underlying container.
+#[track_caller]
 pub unsafe fn get_unchecked_mut<T: ?Sized, I>(v: &mut T, index: I) -> &mut T::Output
-    where T: GetUncheckedMut<I>
+    where T: SliceIndexMut<I>
 {
-    #[cfg(debug_assertions)]
-    v.assert_indexable_with(&index);
+    debug_index_check!(v, &index);
     v.get_unchecked_mut(index)
 }
 
@@ -105,7 +139,7 @@ impl<T> DerefMut for UncheckedIndex<T> {
 }
 
 impl<T, I> Index<I> for UncheckedIndex<T>
-    where T: GetUnchecked<I>
+    where T: SliceIndex<I>
 {
     type Output = T::Output;
 
@@ -119,6 +153,7 @@ impl<T, I> Index<I> for UncheckedIndex<T>
     /// The caller must ensure that `index` is always in bounds of the
     /// underlying container.
     #[inline]
+    #[track_caller]
     fn index(&self, index: I) -> &Self::Output {
         unsafe {
             get_unchecked(&self.0, index)
@@ -127,7 +162,7 @@ impl<T, I> Index<I> for UncheckedIndex<T>
 }
 
 impl<T, I> IndexMut<I> for UncheckedIndex<T>
-    where T: GetUncheckedMut<I>
+    where T: SliceIndexMut<I>
 {
     /// Access the element(s) at `index`, without bounds checks!
     ///
@@ -139,6 +174,7 @@ impl<T, I> IndexMut<I> for UncheckedIndex<T>
     /// The caller must ensure that `index` is always in bounds of the
     /// underlying container.
     #[inline]
+    #[track_caller]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         unsafe {
             get_unchecked_mut(&mut self.0, index)
@@ -146,99 +182,244 @@ impl<T, I> IndexMut<I> for UncheckedIndex<T>
     }
 }
 
-pub trait CheckIndex<I> {
-    /// Assert (using a regular assertion) that the index is valid.
-    /// Must not return if the index is invalid for indexing self.
+impl<S> UncheckedIndex<S> {
+    /// Return a reference to the element(s) at `index`, or `None` if the index
+    /// is out of bounds.
     ///
-    /// ***Panics*** if `index` is invalid.
-    fn assert_indexable_with(&self, index: &I);
-}
-
-impl<'a, T: ?Sized, I> CheckIndex<I> for &'a T where T: CheckIndex<I> {
-    fn assert_indexable_with(&self, index: &I) {
-        (**self).assert_indexable_with(index)
+    /// Unlike the unchecked `Index` path, the bounds check is performed
+    /// unconditionally (not just under debug assertions), exactly like
+    /// `slice::get`, so a single wrapped value can serve both the proven-in-
+    /// bounds fast path and the occasional guarded lookup.
+    pub fn get<I>(&self, index: I) -> Option<&S::Output>
+        where S: SliceIndex<I>
+    {
+        if self.0.is_indexable_with(&index) {
+            Some(unsafe { self.0.get_unchecked(index) })
+        } else {
+            None
+        }
     }
-}
 
-impl<'a, T: ?Sized, I> CheckIndex<I> for &'a mut T where T: CheckIndex<I> {
-    fn assert_indexable_with(&self, index: &I) {
-        (**self).assert_indexable_with(index)
+    /// Return a mutable reference to the element(s) at `index`, or `None` if
+    /// the index is out of bounds.
+    ///
+    /// Like [`get`](#method.get), the bounds check is always performed, exactly
+    /// like `slice::get_mut`.
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut S::Output>
+        where S: SliceIndexMut<I>
+    {
+        if self.0.is_indexable_with(&index) {
+            Some(unsafe { self.0.get_unchecked_mut(index) })
+        } else {
+            None
+        }
     }
 }
 
-impl<T> CheckIndex<usize> for [T] {
-    fn assert_indexable_with(&self, &index: &usize) {
-        assert!(index < self.len(),
-                "index {} is out of bounds in slice of len {}",
-                index, self.len())
-    }
+mod private {
+    /// Seals [`SliceIndex`](../trait.SliceIndex.html) so that downstream crates
+    /// cannot add (potentially unsound) index implementations.
+    pub trait Sealed<I> {}
 }
 
-pub trait GetUnchecked<I>: CheckIndex<I> {
+/// A container that can be indexed by `I` through the (shared) unchecked
+/// accessors.
+///
+/// This sealed trait, together with its [`SliceIndexMut`] extension, replaces
+/// the former `CheckIndex`/`GetUnchecked`/`GetUncheckedMut` split: it carries
+/// the indexing `Output`, the validity predicate and checked assertion used by
+/// the debug bounds checks, and the shared unchecked accessor, the way the
+/// standard library's `SliceIndex<[T]>` does. The free `get_unchecked`
+/// function, `Index`, and `UncheckedIndex::get` all dispatch through it;
+/// mutation lives in [`SliceIndexMut`].
+///
+/// Splitting mutation out keeps a read-only path available for shared
+/// references: `&T` implements `SliceIndex` but not `SliceIndexMut`, so a
+/// `UncheckedIndex<&[T]>` still indexes *unchecked* for reads, while attempting
+/// to mutate through it is a compile error rather than a silent fallback to
+/// checked indexing.
+///
+/// It is *sealed*: only the slice (and wrapped reference) implementations
+/// defined in this crate can exist, so a downstream crate cannot introduce an
+/// unsound one.
+pub trait SliceIndex<I>: private::Sealed<I> {
+    /// The output type returned by indexing (an element for `usize`, a
+    /// subslice for the range types).
     type Output: ?Sized;
+
+    /// Return whether `index` is valid for indexing `self`.
+    ///
+    /// This is the per-index-type validity predicate; it drives the safe
+    /// `get`/`get_mut` accessors, and [`assert_indexable_with`] panics exactly
+    /// when it returns `false`.
+    ///
+    /// [`assert_indexable_with`]: #tymethod.assert_indexable_with
+    fn is_indexable_with(&self, index: &I) -> bool;
+
+    /// Assert (using a regular assertion) that `index` is valid for indexing
+    /// `self`. Must not return if the index is invalid.
+    ///
+    /// ***Panics*** if `index` is invalid.
+    fn assert_indexable_with(&self, index: &I);
+
+    /// Access the element(s) at `index`, without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The index must be in bounds of `self`.
     unsafe fn get_unchecked(&self, index: I) -> &Self::Output;
 }
 
-pub trait GetUncheckedMut<I>: GetUnchecked<I> {
+/// A container that can additionally be indexed *mutably* by `I` through the
+/// unchecked accessors.
+///
+/// This is the mutable extension of [`SliceIndex`]; the free
+/// `get_unchecked_mut` function, `IndexMut`, and `UncheckedIndex::get_mut`
+/// dispatch through it. It is intentionally *not* implemented for shared
+/// references `&T`, which cannot provide mutable access soundly.
+pub trait SliceIndexMut<I>: SliceIndex<I> {
+    /// Access the element(s) at `index` mutably, without bounds checks.
+    ///
+    /// # Safety
+    ///
+    /// The index must be in bounds of `self`.
     unsafe fn get_unchecked_mut(&mut self, index: I) -> &mut Self::Output;
 }
 
-impl<T> GetUnchecked<usize> for [T] {
-    type Output = T;
-    unsafe fn get_unchecked(&self, index: usize) -> &Self::Output {
-        (*self).get_unchecked(index)
+// Forward indexing through references to the referent. A shared reference gets
+// only the read-only `SliceIndex` path (it cannot provide `get_unchecked_mut`
+// soundly); a mutable reference gets both.
+impl<'a, T: ?Sized, I> private::Sealed<I> for &'a T where T: private::Sealed<I> {}
+impl<'a, T: ?Sized, I> private::Sealed<I> for &'a mut T where T: private::Sealed<I> {}
+
+impl<'a, T: ?Sized, I> SliceIndex<I> for &'a T
+    where T: SliceIndex<I>
+{
+    type Output = T::Output;
+
+    fn is_indexable_with(&self, index: &I) -> bool {
+        (**self).is_indexable_with(index)
     }
-}
 
-impl<T> GetUncheckedMut<usize> for [T] {
-    unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut Self::Output {
-        (*self).get_unchecked_mut(index)
+    #[track_caller]
+    fn assert_indexable_with(&self, index: &I) {
+        (**self).assert_indexable_with(index)
     }
-}
 
-impl<'a, T: ?Sized, I> GetUnchecked<I> for &'a T
-    where T: GetUnchecked<I>
-{
-    type Output = T::Output;
     unsafe fn get_unchecked(&self, index: I) -> &Self::Output {
         (**self).get_unchecked(index)
     }
 }
 
-impl<'a, T: ?Sized, I> GetUnchecked<I> for &'a mut T
-    where T: GetUnchecked<I>
+impl<'a, T: ?Sized, I> SliceIndex<I> for &'a mut T
+    where T: SliceIndex<I>
 {
     type Output = T::Output;
+
+    fn is_indexable_with(&self, index: &I) -> bool {
+        (**self).is_indexable_with(index)
+    }
+
+    #[track_caller]
+    fn assert_indexable_with(&self, index: &I) {
+        (**self).assert_indexable_with(index)
+    }
+
     unsafe fn get_unchecked(&self, index: I) -> &Self::Output {
         (**self).get_unchecked(index)
     }
 }
 
-impl<'a, T: ?Sized, I> GetUncheckedMut<I> for &'a mut T
-    where T: GetUncheckedMut<I>
+impl<'a, T: ?Sized, I> SliceIndexMut<I> for &'a mut T
+    where T: SliceIndexMut<I>
 {
     unsafe fn get_unchecked_mut(&mut self, index: I) -> &mut Self::Output {
         (**self).get_unchecked_mut(index)
     }
 }
 
+// Cold, outlined failure arms modeled on `core`'s slice bounds-check panics.
+// Keeping them `#[inline(never)]` leaves the hot indexing path small, and
+// `#[track_caller]` makes the panic point at the user's indexing site.
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn slice_index_len_fail(index: usize, len: usize) -> ! {
+    panic!("index out of bounds: the len is {} but the index is {}", len, index);
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn slice_start_index_len_fail(index: usize, len: usize) -> ! {
+    panic!("range start index {} out of range for slice of length {}", index, len);
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn slice_end_index_len_fail(index: usize, len: usize) -> ! {
+    panic!("range end index {} out of range for slice of length {}", index, len);
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn slice_index_order_fail(index: usize, end: usize) -> ! {
+    panic!("slice index starts at {} but ends at {}", index, end);
+}
+
+impl<T> private::Sealed<usize> for [T] {}
+
+impl<T> SliceIndex<usize> for [T] {
+    type Output = T;
+
+    fn is_indexable_with(&self, &index: &usize) -> bool {
+        index < self.len()
+    }
+
+    #[track_caller]
+    fn assert_indexable_with(&self, &index: &usize) {
+        if index >= self.len() {
+            slice_index_len_fail(index, self.len());
+        }
+    }
+
+    unsafe fn get_unchecked(&self, index: usize) -> &T {
+        (*self).get_unchecked(index)
+    }
+}
+
+impl<T> SliceIndexMut<usize> for [T] {
+    unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
+        (*self).get_unchecked_mut(index)
+    }
+}
+
 macro_rules! impl_slice_range {
-    ($index_type:ty, $self_:ident, $index: ident, $assertion:expr) => {
-        impl<T> CheckIndex<$index_type> for [T] {
+    ($index_type:ty, $self_:ident, $index:ident, $predicate:expr, $assertion:expr) => {
+        impl<T> private::Sealed<$index_type> for [T] {}
+
+        impl<T> SliceIndex<$index_type> for [T] {
+            type Output = [T];
+
+            fn is_indexable_with($self_: &Self, $index: &$index_type) -> bool {
+                $predicate
+            }
+
+            #[track_caller]
             fn assert_indexable_with($self_: &Self, $index: &$index_type) {
                 $assertion
             }
-        }
 
-        impl<T> GetUnchecked<$index_type> for [T] {
-            type Output = [T];
-            unsafe fn get_unchecked(&self, index: $index_type) -> &Self::Output {
+            unsafe fn get_unchecked(&self, index: $index_type) -> &[T] {
                 (*self).get_unchecked(index)
             }
         }
 
-        impl<T> GetUncheckedMut<$index_type> for [T] {
-            unsafe fn get_unchecked_mut(&mut self, index: $index_type) -> &mut Self::Output {
+        impl<T> SliceIndexMut<$index_type> for [T] {
+            unsafe fn get_unchecked_mut(&mut self, index: $index_type) -> &mut [T] {
                 (*self).get_unchecked_mut(index)
             }
         }
@@ -246,21 +427,166 @@ macro_rules! impl_slice_range {
 }
 
 use std::ops::{Range, RangeTo, RangeFrom, RangeFull};
+use std::ops::{RangeInclusive, RangeToInclusive};
+use std::ops::Bound;
+
+impl_slice_range!(Range<usize>, self, index,
+  index.start <= index.end && index.end <= self.len(),
+  {
+    if index.start > index.end { slice_index_order_fail(index.start, index.end); }
+    if index.end > self.len() { slice_end_index_len_fail(index.end, self.len()); }
+  });
+
+impl_slice_range!(RangeTo<usize>, self, index,
+  index.end <= self.len(),
+  {
+    if index.end > self.len() { slice_end_index_len_fail(index.end, self.len()); }
+  });
+
+impl_slice_range!(RangeFrom<usize>, self, index,
+  index.start <= self.len(),
+  {
+    if index.start > self.len() { slice_start_index_len_fail(index.start, self.len()); }
+  });
+
+impl_slice_range!(RangeFull, self, _index, true, { });
+
+impl_slice_range!(RangeInclusive<usize>, self, index,
+  // `end + 1` must not overflow, so reject the `usize::MAX` corner case first.
+  *index.end() != usize::MAX && *index.end() < self.len()
+      && *index.start() <= *index.end() + 1,
+  {
+    if *index.end() == usize::MAX { slice_end_index_len_fail(*index.end(), self.len()); }
+    if *index.end() >= self.len() { slice_end_index_len_fail(*index.end(), self.len()); }
+    if *index.start() > *index.end() + 1 { slice_index_order_fail(*index.start(), *index.end() + 1); }
+  });
+
+impl_slice_range!(RangeToInclusive<usize>, self, index,
+  index.end < self.len(),
+  {
+    if index.end >= self.len() { slice_end_index_len_fail(index.end, self.len()); }
+  });
+
+/// Normalize a pair of `Bound`s into a half-open `start..end`, applying the
+/// same overflow guards `core` uses for the `Excluded(usize::MAX)` and
+/// `Included(usize::MAX)` corner cases. Returns `None` when those guards trip.
+fn try_bounds_to_range(bounds: &(Bound<usize>, Bound<usize>), len: usize)
+    -> Option<Range<usize>>
+{
+    let start = match bounds.0 {
+        Bound::Included(start) => start,
+        Bound::Excluded(start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match bounds.1 {
+        Bound::Included(end) => end.checked_add(1)?,
+        Bound::Excluded(end) => end,
+        Bound::Unbounded => len,
+    };
+    Some(start..end)
+}
 
-impl_slice_range!(Range<usize>, self, index, {
-  assert!(index.start <= index.end, "start={} must be less than end={}", index.start, index.end);
-  assert!(index.end <= self.len(), "end is greater than len={}", self.len());
-});
+/// Like [`try_bounds_to_range`], but panics on the overflow corner cases.
+fn bounds_to_range(bounds: &(Bound<usize>, Bound<usize>), len: usize) -> Range<usize> {
+    try_bounds_to_range(bounds, len)
+        .expect("range bound usize::MAX is out of range")
+}
 
-impl_slice_range!(RangeTo<usize>, self, index, {
-  assert!(index.end <= self.len(), "end is greater than len={}", self.len());
-});
+impl<T> private::Sealed<(Bound<usize>, Bound<usize>)> for [T] {}
 
-impl_slice_range!(RangeFrom<usize>, self, index, {
-  assert!(index.start <= self.len(), "end is greater than len={}", self.len());
-});
+impl<T> SliceIndex<(Bound<usize>, Bound<usize>)> for [T] {
+    type Output = [T];
 
-impl_slice_range!(RangeFull, self, _index, { });
+    fn is_indexable_with(&self, index: &(Bound<usize>, Bound<usize>)) -> bool {
+        match try_bounds_to_range(index, self.len()) {
+            Some(index) => self.is_indexable_with(&index),
+            None => false,
+        }
+    }
+
+    #[track_caller]
+    fn assert_indexable_with(&self, index: &(Bound<usize>, Bound<usize>)) {
+        let index = bounds_to_range(index, self.len());
+        self.assert_indexable_with(&index)
+    }
+
+    unsafe fn get_unchecked(&self, index: (Bound<usize>, Bound<usize>)) -> &[T] {
+        let index = bounds_to_range(&index, self.len());
+        SliceIndex::get_unchecked(self, index)
+    }
+}
+
+impl<T> SliceIndexMut<(Bound<usize>, Bound<usize>)> for [T] {
+    unsafe fn get_unchecked_mut(&mut self, index: (Bound<usize>, Bound<usize>)) -> &mut [T] {
+        let index = bounds_to_range(&index, self.len());
+        SliceIndexMut::get_unchecked_mut(self, index)
+    }
+}
+/// `const fn` unchecked indexing for compile-time contexts.
+///
+/// These are standalone `const`-callable counterparts to the crate's
+/// [`get_unchecked`](../fn.get_unchecked.html) /
+/// [`get_unchecked_mut`](../fn.get_unchecked_mut.html) functions, for the
+/// `[T]` + `usize` and `[T]` + `Range<usize>` cases — the ones expressible
+/// with the stable `const` slice primitives. They let `const` evaluators and
+/// build-time table generation reuse this crate's audited unchecked accessors
+/// instead of hand-rolling raw pointer arithmetic.
+///
+/// The trait-dispatched accessors can't be `const fn` on stable (trait methods
+/// aren't `const`, and there's no function overloading), so the range variants
+/// are spelled `*_range`.
+///
+/// Unlike the regular accessors, these perform **no** debug bounds check: the
+/// check can't run in `const` context, and the `const` evaluator already
+/// rejects genuinely out-of-bounds pointer arithmetic at compile time. At
+/// runtime the same safety contract applies as for the other accessors.
+///
+/// The module is gated on the `const_index` feature so that the crate keeps
+/// building on toolchains too old for these `const` primitives.
+#[cfg(feature = "const_index")]
+pub mod const_index {
+    use std::ops::Range;
+
+    /// Access the element at `index`, without bounds checks, in `const`
+    /// context. See the [module docs](index.html) for the safety contract.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds of `slice`.
+    pub const unsafe fn get_unchecked<T>(slice: &[T], index: usize) -> &T {
+        &*slice.as_ptr().add(index)
+    }
+
+    /// Mutably access the element at `index`, without bounds checks, in
+    /// `const` context.
+    ///
+    /// # Safety
+    ///
+    /// `index` must be in bounds of `slice`.
+    pub const unsafe fn get_unchecked_mut<T>(slice: &mut [T], index: usize) -> &mut T {
+        &mut *slice.as_mut_ptr().add(index)
+    }
+
+    /// Access the subslice at `index`, without bounds checks, in `const`
+    /// context.
+    ///
+    /// # Safety
+    ///
+    /// `index.start <= index.end` and `index.end <= slice.len()`.
+    pub const unsafe fn get_unchecked_range<T>(slice: &[T], index: Range<usize>) -> &[T] {
+        std::slice::from_raw_parts(slice.as_ptr().add(index.start), index.end - index.start)
+    }
+
+    /// Mutably access the subslice at `index`, without bounds checks, in
+    /// `const` context.
+    ///
+    /// # Safety
+    ///
+    /// `index.start <= index.end` and `index.end <= slice.len()`.
+    pub const unsafe fn get_unchecked_range_mut<T>(slice: &mut [T], index: Range<usize>) -> &mut [T] {
+        std::slice::from_raw_parts_mut(slice.as_mut_ptr().add(index.start), index.end - index.start)
+    }
+}
 
 
 #[cfg(test)]
@@ -335,4 +661,191 @@ mod tests {
         }
         assert_eq!(data, [0, 0, 0, 0, 0, 0, 0, 1]);
     }
+
+    #[test]
+    fn inclusive_range() {
+        let data = [0, 1, 2, 3, 4, 5, 6, 7];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            assert_eq!(&data[2..=4], &[2, 3, 4]);
+            assert_eq!(&data[..=3], &[0, 1, 2, 3]);
+            // the empty inclusive range at the end of the slice is valid
+            assert_eq!(&data[8..=7], &[] as &[i32]);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn debug_oob_check_range_inclusive() {
+        let data = [0; 8];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            println!("{:?}", &data[5..=9]);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn debug_oob_check_range_to_inclusive() {
+        let data = [0; 8];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            println!("{:?}", &data[..=8]);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn debug_oob_check_inclusive_end_max() {
+        // `end == usize::MAX` is rejected before the `+ 1` would overflow
+        let data = [0; 8];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            println!("{:?}", &data[0..=usize::MAX]);
+        }
+    }
+
+    #[test]
+    fn bound_pair() {
+        use std::ops::Bound::*;
+        let data = [0, 1, 2, 3, 4, 5, 6, 7];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            assert_eq!(&data[(Included(2), Excluded(5))], &[2, 3, 4]);
+            assert_eq!(&data[(Included(2), Included(4))], &[2, 3, 4]);
+            assert_eq!(&data[(Excluded(1), Unbounded)], &[2, 3, 4, 5, 6, 7]);
+            assert_eq!(&data[(Unbounded, Unbounded)], &data[..]);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic]
+    fn debug_oob_check_bound_pair_included_max() {
+        use std::ops::Bound::*;
+        let data = [0; 8];
+        unsafe {
+            let data = unchecked_index(&data[..]);
+            println!("{:?}", &data[(Included(0), Included(usize::MAX))]);
+        }
+    }
+
+    // With `debug_abort` enabled a failing debug check must abort the process
+    // rather than unwind. We re-exec this test binary in a child that trips an
+    // out-of-bounds index and assert the child dies on `SIGABRT` (signal 6)
+    // without running any code past the check.
+    #[cfg(all(debug_assertions, feature = "debug_abort"))]
+    #[test]
+    fn debug_abort_on_failed_check() {
+        use std::process::Command;
+        if std::env::var_os("UNCHECKED_INDEX_ABORT_CHILD").is_some() {
+            let data = [0; 4];
+            let data = unsafe { unchecked_index(&data[..]) };
+            let _ = data[9];
+            // Unreachable: the check above must have aborted the process.
+            println!("reached code past the failed check");
+            return;
+        }
+        let exe = std::env::current_exe().unwrap();
+        let status = Command::new(exe)
+            .args(&["--exact", "--nocapture", "tests::debug_abort_on_failed_check"])
+            .env("UNCHECKED_INDEX_ABORT_CHILD", "1")
+            .output()
+            .unwrap();
+        assert!(!status.status.success());
+        assert!(!String::from_utf8_lossy(&status.stdout).contains("reached code past"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            assert_eq!(status.status.signal(), Some(6));
+        }
+    }
+
+    // The cold failure functions carry `#[track_caller]`, so a tripped debug
+    // check must point at the user's indexing site, not at this crate, and use
+    // `core`'s slice-style message. (Skipped under `debug_abort`, where the
+    // failure aborts instead of unwinding and so cannot be caught.)
+    #[cfg(all(debug_assertions, not(feature = "debug_abort")))]
+    #[test]
+    fn debug_check_panics_at_caller_with_slice_message() {
+        use std::panic;
+        use std::sync::{Arc, Mutex};
+
+        let location: Arc<Mutex<Option<(String, u32)>>> = Arc::new(Mutex::new(None));
+        let message: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+        let loc = location.clone();
+        let msg = message.clone();
+        panic::set_hook(Box::new(move |info| {
+            if let Some(l) = info.location() {
+                *loc.lock().unwrap() = Some((l.file().to_string(), l.line()));
+            }
+            let p = info.payload();
+            *msg.lock().unwrap() = p.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| p.downcast_ref::<String>().cloned());
+        }));
+
+        let buf = [0i32; 4];
+        let data = unsafe { unchecked_index(&buf[..]) };
+        let expected_line = line!() + 1;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| { let _ = data[9]; }));
+
+        let _ = panic::take_hook();
+        assert!(result.is_err());
+
+        let (file, line) = location.lock().unwrap().clone().unwrap();
+        assert!(file.ends_with("lib.rs"));
+        assert_eq!(line, expected_line);
+        assert_eq!(
+            message.lock().unwrap().as_deref(),
+            Some("index out of bounds: the len is 4 but the index is 9"),
+        );
+    }
+
+    #[test]
+    fn get_and_get_mut() {
+        let mut data = [0, 1, 2, 3];
+        unsafe {
+            let mut data = unchecked_index(&mut data[..]);
+            assert_eq!(data.get(2), Some(&2));
+            assert_eq!(data.get(4), None);
+            assert_eq!(data.get(1..3), Some(&[1, 2][..]));
+            assert_eq!(data.get(2..9), None);
+
+            assert_eq!(data.get_mut(0), Some(&mut 0));
+            assert_eq!(data.get_mut(9), None);
+            if let Some(slot) = data.get_mut(3) {
+                *slot = 30;
+            }
+        }
+        assert_eq!(data, [0, 1, 2, 30]);
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn get_is_checked_in_release() {
+        // the whole point of `get`/`get_mut` is an *always*-checked path, even
+        // in release where the `Index` impl omits the bounds check
+        let mut data = [0; 4];
+        unsafe {
+            let mut data = unchecked_index(&mut data[..]);
+            assert!(data.get(10).is_none());
+            assert!(data.get(2..9).is_none());
+            assert!(data.get(0).is_some());
+            assert!(data.get_mut(10).is_none());
+        }
+    }
+
+    #[cfg(feature = "const_index")]
+    #[test]
+    fn const_index_usable_in_const() {
+        // evaluating these in `const` items proves the accessors are `const fn`
+        const DATA: [i32; 4] = [10, 20, 30, 40];
+        const ELEM: &i32 = unsafe { const_index::get_unchecked(&DATA, 2) };
+        const SUB: &[i32] = unsafe { const_index::get_unchecked_range(&DATA, 1..3) };
+        assert_eq!(*ELEM, 30);
+        assert_eq!(SUB, &[20, 30]);
+    }
 }